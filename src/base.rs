@@ -1,6 +1,10 @@
 //! The module defines the `Future` trait, as well as a simple struct that wraps any type T
 //! and implements the `Future` trait.
 
+use std::any::Any;
+use std::panic::{self, UnwindSafe};
+use std::thread;
+
 /// The trait represents a value that will become available in the future.
 /// Concrete implementations of the trait only need to provide the implementation of the
 /// `await` method, which is to return the value once it becomes available, possibly even
@@ -49,6 +53,51 @@ pub trait Future {
     {
         bind(self, f)
     }
+
+    /// Returns a new `Future` that resolves to a tuple of both `self`'s and `other`'s
+    /// output, once both have resolved.
+    ///
+    /// This is a purely sequential combinator: `self` is awaited to completion before
+    /// `other` is even polled. For the two futures to make progress concurrently, use
+    /// `join_concurrent` instead.
+    #[inline]
+    fn join<Other>(self, other: Other) -> FutureJoin<Self, Other>
+            where Self: Sized,
+                  Other: Future
+    {
+        join(self, other)
+    }
+
+    /// Returns a new `Future` that awaits `self` on a background thread while `other` is
+    /// awaited on the calling thread, joining the two results into a tuple once both are
+    /// available.
+    ///
+    /// Unlike `join`, this gives both futures a chance to make progress at the same time.
+    /// Since `self` is moved onto a `std::thread`, its output and the future itself must
+    /// be `Send + 'static`. If awaiting `self` panics on the worker thread, that panic is
+    /// propagated through the `JoinHandle` and will, in turn, panic the calling thread
+    /// once `await` is invoked.
+    #[inline]
+    fn join_concurrent<Other>(self, other: Other) -> FutureJoinConcurrent<Self, Other>
+            where Self: Sized + Send + 'static,
+                  Self::Output: Send + 'static,
+                  Other: Future
+    {
+        join_concurrent(self, other)
+    }
+
+    /// Returns a new `Future` whose `await` wraps the original `await` call in
+    /// `std::panic::catch_unwind`, turning a panic raised while resolving `self` into an
+    /// `Err` instead of unwinding the calling thread.
+    ///
+    /// Requires `Self: UnwindSafe` so that the bound is explicit at the call site, rather
+    /// than surfacing deep inside `catch_unwind`'s own implementation.
+    #[inline]
+    fn catch_unwind(self) -> FutureCatchUnwind<Self>
+            where Self: Sized + UnwindSafe
+    {
+        catch_unwind(self)
+    }
 }
 
 /// A simple implementation of the `Future` trait that returns the wrapped object from its
@@ -122,6 +171,98 @@ pub fn bind<T, U, OrigFuture, NextFuture, Func>(
     }
 }
 
+/// The struct represents the future returned by the `Future::join` method.
+pub struct FutureJoin<First, Second> {
+    first: First,
+    second: Second,
+}
+
+impl<First, Second> Future for FutureJoin<First, Second>
+        where First: Future,
+              Second: Future {
+    type Output = (First::Output, Second::Output);
+
+    fn await(self) -> Self::Output {
+        let first = self.first.await();
+        let second = self.second.await();
+        (first, second)
+    }
+}
+
+/// Sequentially awaits `first` and then `second`, resolving to a tuple of both outputs.
+///
+/// See `Future::join` for a description of the (sequential) semantics, and
+/// `join_concurrent` for a variant that awaits both futures concurrently.
+#[inline]
+pub fn join<First, Second>(first: First, second: Second) -> FutureJoin<First, Second>
+        where First: Future,
+              Second: Future {
+    FutureJoin { first: first, second: second }
+}
+
+/// The struct represents the future returned by the `Future::join_concurrent` method.
+pub struct FutureJoinConcurrent<First, Second> {
+    first: First,
+    second: Second,
+}
+
+impl<First, Second> Future for FutureJoinConcurrent<First, Second>
+        where First: Future + Send + 'static,
+              First::Output: Send + 'static,
+              Second: Future {
+    type Output = (First::Output, Second::Output);
+
+    fn await(self) -> Self::Output {
+        let FutureJoinConcurrent { first, second } = self;
+        let handle = thread::spawn(move || first.await());
+        let second = second.await();
+        let first = handle.join().expect("the join_concurrent worker thread panicked");
+        (first, second)
+    }
+}
+
+/// Awaits `first` on a background thread and `second` on the calling thread, joining
+/// their results into a tuple once both are available.
+///
+/// See `Future::join_concurrent` for details, including the `Send + 'static` bounds this
+/// requires of `first`, and the panic propagation behaviour.
+#[inline]
+pub fn join_concurrent<First, Second>(first: First, second: Second) -> FutureJoinConcurrent<First, Second>
+        where First: Future + Send + 'static,
+              First::Output: Send + 'static,
+              Second: Future {
+    FutureJoinConcurrent { first: first, second: second }
+}
+
+/// The struct represents the future returned by the `Future::catch_unwind` method.
+pub struct FutureCatchUnwind<F> {
+    inner: F,
+}
+
+impl<F> Future for FutureCatchUnwind<F>
+        where F: Future + UnwindSafe {
+    type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+    fn await(self) -> Self::Output {
+        let inner = self.inner;
+        panic::catch_unwind(move || inner.await())
+    }
+}
+
+/// Wraps `future` so that a panic raised while resolving it is caught and returned as an
+/// `Err`, rather than unwinding the calling thread.
+///
+/// This is especially useful in this crate since `await` often blocks on a real thread
+/// (for instance `ChannelFuture::await`, which panics if its `Sender` is dropped without
+/// sending a value), so callers otherwise have no
+/// way to recover from a panic inside a mapped closure, or a disconnected channel, short of
+/// unwinding the whole thread.
+#[inline]
+pub fn catch_unwind<F>(future: F) -> FutureCatchUnwind<F>
+        where F: Future + UnwindSafe {
+    FutureCatchUnwind { inner: future }
+}
+
 /// Lifts the given object into a `Future` context. This means that the returned type implements
 /// the `Future` trait in such a way that its `await` method will return the originally given
 /// object.
@@ -201,4 +342,32 @@ pub mod tests {
         let res = future.bind(|val| lift(val*2));
         assert_eq!(res.await(), 200);
     }
+
+    #[test]
+    fn test_join_combines_both_outputs() {
+        let first = lift(5u8);
+        let second = lift("hello");
+        let res = first.join(second);
+        assert_eq!(res.await(), (5u8, "hello"));
+    }
+
+    #[test]
+    fn test_join_concurrent_combines_both_outputs() {
+        let first = lift(5u8);
+        let second = lift(10u8);
+        let res = first.join_concurrent(second);
+        assert_eq!(res.await(), (5u8, 10u8));
+    }
+
+    #[test]
+    fn test_catch_unwind_wraps_ok_value() {
+        let future = lift(5u8).catch_unwind();
+        assert_eq!(future.await().unwrap(), 5u8);
+    }
+
+    #[test]
+    fn test_catch_unwind_catches_panic_from_mapped_closure() {
+        let future = lift(5u8).map(|_| -> u8 { panic!("boom") }).catch_unwind();
+        assert!(future.await().is_err());
+    }
 }
@@ -0,0 +1,170 @@
+//! Provides a small M:N executor: a fixed pool of worker threads draining a shared queue of
+//! blocking futures.
+//!
+//! Every `Future` in this crate blocks the thread that `await`s it, so a single logical
+//! future occupies a thread for its whole lifetime. `Executor` lets callers fan many such
+//! futures out across a fixed number `N` of worker threads instead of spawning one thread
+//! per future: `submit` boxes the future as a job, enqueues it, and immediately returns a
+//! `ChannelFuture` that resolves once some worker has picked up the job and awaited it. The
+//! returned `ChannelFuture`s compose as usual with `map`/`bind`/`join`/`select_all`.
+//!
+//! Since an awaited future occupies its worker for as long as it blocks, sizing `N` matters:
+//! too few workers and unrelated work queues up behind a single slow future.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use base::Future;
+use mpsc::ChannelFuture;
+
+type Job = Box<dyn FnMut() + Send>;
+
+/// The queue and shutdown flag share a single `Mutex` so that a worker's check of
+/// `shutdown` and its subsequent `Condvar::wait` are atomic with respect to `Drop` setting
+/// `shutdown` and notifying: otherwise a `notify_all` landing in the gap between the check
+/// and the `wait` would be lost, parking the worker (and `Drop`'s `join`) forever.
+struct Queue {
+    jobs: VecDeque<Job>,
+    shutdown: bool,
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    ready: Condvar,
+}
+
+/// A fixed pool of `n_workers` threads that drain a shared queue of submitted futures.
+pub struct Executor {
+    shared: Arc<Shared>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl Executor {
+    /// Spins up `n_workers` worker threads, each draining the shared job queue as work is
+    /// submitted via `submit`.
+    pub fn new(n_workers: usize) -> Executor {
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(Queue { jobs: VecDeque::new(), shutdown: false }),
+            ready: Condvar::new(),
+        });
+
+        let workers = (0..n_workers).map(|_| {
+            let shared = shared.clone();
+            thread::spawn(move || Executor::worker_loop(shared))
+        }).collect();
+
+        Executor { shared: shared, workers: workers }
+    }
+
+    fn worker_loop(shared: Arc<Shared>) {
+        loop {
+            let mut job = match Executor::next_job(&shared) {
+                Some(job) => job,
+                None => return,
+            };
+            job();
+        }
+    }
+
+    fn next_job(shared: &Arc<Shared>) -> Option<Job> {
+        let mut queue = shared.queue.lock().unwrap();
+        loop {
+            if let Some(job) = queue.jobs.pop_front() {
+                return Some(job);
+            }
+            if queue.shutdown {
+                return None;
+            }
+            queue = shared.ready.wait(queue).unwrap();
+        }
+    }
+
+    /// Boxes `f` as a job, enqueues it and immediately returns a `ChannelFuture` resolving
+    /// to its output once some worker has dequeued and awaited it.
+    pub fn submit<F>(&self, f: F) -> ChannelFuture<F::Output>
+            where F: Future + Send + 'static,
+                  F::Output: Send + 'static {
+        let (future, resolver) = ChannelFuture::new();
+        // `f` and `resolver` are only ever used once the worker dequeues the job, so the
+        // `FnMut` bound required to store the job in the queue is trivially satisfied by an
+        // `FnOnce` captured behind an `Option`.
+        let mut cell = Some((f, resolver));
+        let job: Job = Box::new(move || {
+            if let Some((f, resolver)) = cell.take() {
+                let _ = resolver.send(f.await());
+            }
+        });
+
+        {
+            let mut queue = self.shared.queue.lock().unwrap();
+            queue.jobs.push_back(job);
+        }
+        self.shared.ready.notify_one();
+
+        future
+    }
+}
+
+impl Drop for Executor {
+    /// Signals all workers to stop once the queue is drained, then joins them, so that any
+    /// already-submitted futures are still awaited before the executor is torn down.
+    fn drop(&mut self) {
+        self.shared.queue.lock().unwrap().shutdown = true;
+        self.shared.ready.notify_all();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::thread;
+
+    use super::Executor;
+    use base::{Future, lift};
+    use mpsc::ChannelFuture;
+
+    #[test]
+    fn test_submit_resolves_to_futures_output() {
+        let executor = Executor::new(2);
+        let future = executor.submit(lift(5u8).map(|val| val * 2));
+        assert_eq!(future.await(), 10u8);
+    }
+
+    #[test]
+    fn test_many_futures_fan_out_across_fewer_workers() {
+        let executor = Executor::new(2);
+        let futures: Vec<_> = (0..8u8).map(|i| {
+            executor.submit(lift(i).map(|val| val + 1))
+        }).collect();
+
+        let results: Vec<u8> = futures.into_iter().map(|f| f.await()).collect();
+        assert_eq!(results, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_drop_drains_queued_work_before_joining_workers() {
+        let (tally_future, tally_resolver) = ChannelFuture::new();
+        let (tx, rx) = mpsc::channel();
+
+        {
+            let executor = Executor::new(1);
+            for _ in 0..5 {
+                let tx = tx.clone();
+                executor.submit(lift(()).map(move |_| {
+                    tx.send(()).unwrap();
+                }));
+            }
+            thread::spawn(move || {
+                let count = (0..5).filter(|_| rx.recv().is_ok()).count();
+                assert!(tally_resolver.send(count).is_ok());
+            });
+            // `executor` is dropped here, which must wait for the queued jobs to run.
+        }
+
+        assert_eq!(tally_future.await(), 5);
+    }
+}
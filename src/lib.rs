@@ -64,12 +64,23 @@
 //! [gj](https://github.com/dwrensha/gj) for a future implementation that is async/evented.
 
 mod base;
+pub mod executor;
 pub mod mpsc;
+pub mod select;
+pub mod shared;
 
 pub use base::Future;
 pub use base::FutureThen;
 pub use base::FutureBind;
 pub use base::FutureWrap;
+pub use base::FutureJoin;
+pub use base::FutureJoinConcurrent;
+pub use base::FutureCatchUnwind;
 pub use base::lift;
 
 pub use mpsc::ChannelFuture;
+pub use mpsc::spawn;
+pub use mpsc::{TimeoutError, AbortHandle, Aborted, AbortableChannelFuture};
+pub use select::{SelectFuture, select_all};
+pub use shared::{SharedFuture, SharedFutureHandle};
+pub use executor::Executor;
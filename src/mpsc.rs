@@ -2,7 +2,13 @@
 //! is expected to arrive on an `std::mpsc::Receiver`.
 
 use base::Future;
-use std::sync::mpsc;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// How often `AbortableChannelFuture::await` re-checks the abort channel while waiting for
+/// the value channel to produce a result.
+const ABORT_POLL_INTERVAL: Duration = Duration::from_millis(20);
 
 /// An implementation of the `Future` trait that wraps an `std::mpsc::Receiver`. It resolves
 /// to the first un-read value on the channel, possibly blocking until one is available.
@@ -27,20 +33,132 @@ impl<T> ChannelFuture<T> {
     pub fn from_receiver(rx: mpsc::Receiver<T>) -> ChannelFuture<T> {
         ChannelFuture { rx: rx }
     }
+
+    /// Like `await`, but never panics: a dropped `Sender` is reported as an `Err` rather
+    /// than unwinding the calling thread.
+    fn recv(&self) -> Result<T, mpsc::RecvError> {
+        self.rx.recv()
+    }
+
+    /// Like `await`, but gives up waiting once `timeout` elapses. On timeout, or if the
+    /// `Sender` was dropped without sending, the `ChannelFuture` is handed back inside the
+    /// `Err` so that the caller can retry the wait.
+    pub fn await_timeout(self, timeout: Duration) -> Result<T, TimeoutError<T>> {
+        match self.rx.recv_timeout(timeout) {
+            Ok(value) => Ok(value),
+            Err(_) => Err(TimeoutError { future: self }),
+        }
+    }
+
+    /// Splits this `ChannelFuture` into a future that can be cancelled and the
+    /// `AbortHandle` used to cancel it. Calling `abort()` on the handle causes the
+    /// returned future's `await` to resolve to `Err(Aborted)` instead of blocking
+    /// indefinitely (or forever, if the original `Sender` is never used).
+    pub fn abortable(self) -> (AbortableChannelFuture<T>, AbortHandle) {
+        let (abort_tx, abort_rx) = mpsc::channel();
+        (
+            AbortableChannelFuture { rx: self.rx, abort_rx: abort_rx },
+            AbortHandle { tx: abort_tx },
+        )
+    }
 }
 
 impl<T> Future for ChannelFuture<T> {
     type Output = T;
     fn await(self) -> T {
-        self.rx.recv().unwrap()
+        self.recv().expect("the ChannelFuture's Sender was dropped without sending a value")
+    }
+}
+
+/// The error returned by `ChannelFuture::await_timeout` when the timeout elapses (or the
+/// `Sender` was dropped) before a value arrived. Holds onto the original `ChannelFuture` so
+/// that the wait can be retried.
+pub struct TimeoutError<T> {
+    future: ChannelFuture<T>,
+}
+
+impl<T> TimeoutError<T> {
+    /// Recovers the `ChannelFuture` so the caller can retry `await` or `await_timeout`.
+    pub fn into_future(self) -> ChannelFuture<T> {
+        self.future
+    }
+}
+
+/// A handle used to cancel an `AbortableChannelFuture`. See `ChannelFuture::abortable`.
+pub struct AbortHandle {
+    tx: mpsc::Sender<()>,
+}
+
+impl AbortHandle {
+    /// Cancels the associated `AbortableChannelFuture`, causing its `await` to resolve to
+    /// `Err(Aborted)`. Has no effect if the future has already resolved.
+    pub fn abort(&self) {
+        let _ = self.tx.send(());
     }
 }
 
+/// The error returned by an `AbortableChannelFuture`'s `await` when its `AbortHandle` was
+/// used to cancel the wait.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Aborted;
+
+/// A `ChannelFuture` that can be cancelled via a paired `AbortHandle`, produced by
+/// `ChannelFuture::abortable`.
+pub struct AbortableChannelFuture<T> {
+    rx: mpsc::Receiver<T>,
+    abort_rx: mpsc::Receiver<()>,
+}
+
+impl<T> Future for AbortableChannelFuture<T> {
+    type Output = Result<T, Aborted>;
+
+    fn await(self) -> Result<T, Aborted> {
+        // `std::sync::mpsc` has no stable primitive to block on two receivers at once, so
+        // we poll the value channel with a short timeout, checking for an abort signal in
+        // between each attempt.
+        loop {
+            if self.abort_rx.try_recv().is_ok() {
+                return Err(Aborted);
+            }
+            match self.rx.recv_timeout(ABORT_POLL_INTERVAL) {
+                Ok(value) => return Ok(value),
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => {
+                    // No value will ever arrive; keep polling for an abort signal, since
+                    // there is no sensible `T` to manufacture in its place.
+                    thread::sleep(ABORT_POLL_INTERVAL);
+                }
+            }
+        }
+    }
+}
+
+/// Runs `f` on a background thread and returns a `ChannelFuture` resolving to its return
+/// value once the thread completes, following the old `std::sync::Future::spawn` design.
+///
+/// # Example
+///
+/// ```rust
+/// use zukunft::{Future, spawn};
+/// let future = spawn(|| 2 + 2);
+/// assert_eq!(future.await(), 4);
+/// ```
+pub fn spawn<T, Func>(f: Func) -> ChannelFuture<T>
+        where T: Send + 'static,
+              Func: FnOnce() -> T + Send + 'static {
+    let (future, resolver) = ChannelFuture::new();
+    thread::spawn(move || {
+        let _ = resolver.send(f());
+    });
+    future
+}
+
 #[cfg(test)]
 mod tests {
     use std::thread;
+    use std::time::Duration;
 
-    use super::ChannelFuture;
+    use super::{Aborted, ChannelFuture, spawn};
     use base::{Future, lift};
 
     #[test]
@@ -82,6 +200,61 @@ mod tests {
         assert_eq!(future.await(), 60);
     }
 
+    #[test]
+    fn test_spawn_runs_closure_on_background_thread() {
+        let future = spawn(|| {
+            thread::sleep_ms(10);
+            5u8
+        });
+        assert_eq!(future.await(), 5u8);
+    }
+
+    #[test]
+    fn test_await_timeout_resolves_if_value_arrives_in_time() {
+        let (future, resolver) = ChannelFuture::new();
+        thread::spawn(move || {
+            thread::sleep_ms(10);
+            assert!(resolver.send(10u8).is_ok());
+        });
+        match future.await_timeout(Duration::from_millis(200)) {
+            Ok(value) => assert_eq!(value, 10u8),
+            Err(_) => panic!("expected the future to resolve before the timeout"),
+        }
+    }
+
+    #[test]
+    fn test_await_timeout_returns_usable_future_on_timeout() {
+        let (future, resolver) = ChannelFuture::new();
+        let future = match future.await_timeout(Duration::from_millis(10)) {
+            Ok(_) => panic!("should not have resolved yet"),
+            Err(timeout) => timeout.into_future(),
+        };
+        assert!(resolver.send(42u8).is_ok());
+        assert_eq!(future.await(), 42u8);
+    }
+
+    #[test]
+    fn test_abortable_resolves_normally_when_not_aborted() {
+        let (future, resolver) = ChannelFuture::new();
+        let (future, _handle) = future.abortable();
+        thread::spawn(move || {
+            thread::sleep_ms(10);
+            assert!(resolver.send(7u8).is_ok());
+        });
+        assert_eq!(future.await(), Ok(7u8));
+    }
+
+    #[test]
+    fn test_abortable_reports_aborted_once_cancelled() {
+        let (future, _resolver) = ChannelFuture::<u8>::new();
+        let (future, handle) = future.abortable();
+        thread::spawn(move || {
+            thread::sleep_ms(10);
+            handle.abort();
+        });
+        assert_eq!(future.await(), Err(Aborted));
+    }
+
     #[test]
     fn test_composes_with_wrapped_bind() {
         let (future, resolver) = ChannelFuture::new();
@@ -0,0 +1,101 @@
+//! Provides a `select` subsystem that resolves to whichever of several futures completes
+//! first, analogous to futures-util's `select_all`.
+//!
+//! Since `await` blocks per-future, each input future is spawned on its own thread. Every
+//! thread forwards its result, tagged with its source index, into one shared channel; the
+//! combined `SelectFuture`'s `await` does a single `recv()` on that channel. Losing threads
+//! are not stopped --- they simply keep running until their own future resolves.
+
+use base::Future;
+use mpsc::ChannelFuture;
+use std::sync::mpsc;
+use std::thread;
+
+/// The future returned by `select_all`. Resolves to `(usize, T)` --- the index of whichever
+/// input future completed first, together with its value.
+pub struct SelectFuture<T> {
+    rx: mpsc::Receiver<(usize, T)>,
+}
+
+impl<T> Future for SelectFuture<T> {
+    type Output = (usize, T);
+
+    fn await(self) -> (usize, T) {
+        self.rx.recv().expect("all select_all futures disconnected without resolving")
+    }
+}
+
+/// Races the given futures against one another, spawning each on its own thread.
+///
+/// Returns the combined `SelectFuture`, which resolves to the winning future's index and
+/// value, together with a `Vec` of `ChannelFuture`s (in the same order as `futures`) that
+/// callers can continue awaiting for the remaining results, including the winner's --- the
+/// winner's `ChannelFuture` simply resolves immediately, having already been raced.
+///
+/// Requires `F: Send + 'static` (and likewise for its output) since every future is moved
+/// onto a dedicated thread, and `F::Output: Clone` since the winning value is observed both
+/// through the combined `SelectFuture` and through that future's own `ChannelFuture`.
+pub fn select_all<F>(futures: Vec<F>) -> (SelectFuture<F::Output>, Vec<ChannelFuture<F::Output>>)
+        where F: Future + Send + 'static,
+              F::Output: Clone + Send + 'static {
+    let (tx, rx) = mpsc::channel();
+    let mut remaining = Vec::with_capacity(futures.len());
+
+    for (index, future) in futures.into_iter().enumerate() {
+        let (own_future, own_resolver) = ChannelFuture::new();
+        remaining.push(own_future);
+
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let value = future.await();
+            let _ = own_resolver.send(value.clone());
+            let _ = tx.send((index, value));
+        });
+    }
+
+    (SelectFuture { rx: rx }, remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::select_all;
+    use base::Future;
+    use mpsc::ChannelFuture;
+
+    #[test]
+    fn test_select_all_resolves_to_first_finisher() {
+        let (slow_future, slow_resolver) = ChannelFuture::new();
+        let (fast_future, fast_resolver) = ChannelFuture::new();
+        thread::spawn(move || {
+            thread::sleep_ms(50);
+            assert!(slow_resolver.send(1u8).is_ok());
+        });
+        thread::spawn(move || {
+            assert!(fast_resolver.send(2u8).is_ok());
+        });
+
+        let (winner, _remaining) = select_all(vec![slow_future, fast_future]);
+        assert_eq!(winner.await(), (1, 2u8));
+    }
+
+    #[test]
+    fn test_select_all_remaining_futures_still_resolve() {
+        let (first, first_resolver) = ChannelFuture::new();
+        let (second, second_resolver) = ChannelFuture::new();
+        // Resolve `first` before racing so it is guaranteed to win regardless of thread
+        // scheduling; `second` is deliberately held back until after the winner is known.
+        assert!(first_resolver.send(1u8).is_ok());
+
+        let (winner, mut remaining) = select_all(vec![first, second]);
+        let (index, value) = winner.await();
+        assert_eq!((index, value), (0, 1u8));
+
+        assert!(second_resolver.send(2u8).is_ok());
+
+        // Both the winner's and the loser's `ChannelFuture` can still be awaited.
+        assert_eq!(remaining.remove(0).await(), 1u8);
+        assert_eq!(remaining.remove(0).await(), 2u8);
+    }
+}
@@ -0,0 +1,123 @@
+//! Provides `SharedFuture`, a wrapper that allows a `Future`'s resolved value to be
+//! observed more than once, mirroring the old `get`/`get_ref` caching behaviour.
+//!
+//! Since `Future::await` consumes `self`, a plain future's result can only be observed
+//! once. `SharedFuture` drives the inner future to completion on the first `await_ref`
+//! call and caches the value, returning the stored reference on every subsequent call so
+//! several downstream computations can observe the same resolved value without re-running
+//! the work.
+
+use std::sync::{Arc, Mutex, OnceLock};
+
+use base::Future;
+
+/// Wraps a `Future` so that its resolved value can be observed multiple times.
+///
+/// The inner future is driven to completion (and the resulting value cached in a
+/// once-style cell) the first time `await_ref` is called; every subsequent call simply
+/// returns a reference to the cached value, without re-running the future.
+pub struct SharedFuture<F>
+        where F: Future {
+    pending: Mutex<Option<F>>,
+    resolved: OnceLock<F::Output>,
+}
+
+impl<F> SharedFuture<F>
+        where F: Future {
+    /// Wraps `future` so that its output can be awaited more than once.
+    pub fn new(future: F) -> SharedFuture<F> {
+        SharedFuture { pending: Mutex::new(Some(future)), resolved: OnceLock::new() }
+    }
+
+    /// Drives the inner future to completion on first call and caches its output; every
+    /// call (including the first) returns a reference to the cached value.
+    ///
+    /// Blocks the calling thread if another thread is currently resolving the inner
+    /// future for the first time.
+    pub fn await_ref(&self) -> &F::Output {
+        self.resolved.get_or_init(|| {
+            let future = self.pending.lock().unwrap().take()
+                .expect("SharedFuture's inner future already taken without resolving");
+            future.await()
+        })
+    }
+}
+
+/// A cheaply `Clone`able handle to a `SharedFuture`, allowing the same shared result to be
+/// awaited from multiple threads.
+pub struct SharedFutureHandle<F>
+        where F: Future {
+    inner: Arc<SharedFuture<F>>,
+}
+
+impl<F> SharedFutureHandle<F>
+        where F: Future {
+    /// Wraps `future` in a `SharedFuture` behind a `Clone`able, `Arc`-backed handle.
+    pub fn new(future: F) -> SharedFutureHandle<F> {
+        SharedFutureHandle { inner: Arc::new(SharedFuture::new(future)) }
+    }
+
+    /// See `SharedFuture::await_ref`.
+    pub fn await_ref(&self) -> &F::Output {
+        self.inner.await_ref()
+    }
+}
+
+impl<F> Clone for SharedFutureHandle<F>
+        where F: Future {
+    fn clone(&self) -> SharedFutureHandle<F> {
+        SharedFutureHandle { inner: self.inner.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use super::{SharedFuture, SharedFutureHandle};
+    use base::{Future, lift};
+
+    struct CountingFuture {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Future for CountingFuture {
+        type Output = u8;
+        fn await(self) -> u8 {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            42
+        }
+    }
+
+    #[test]
+    fn test_await_ref_resolves_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let shared = SharedFuture::new(CountingFuture { calls: calls.clone() });
+
+        assert_eq!(*shared.await_ref(), 42);
+        assert_eq!(*shared.await_ref(), 42);
+        assert_eq!(*shared.await_ref(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_handle_can_be_cloned_and_shared_across_threads() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let handle = SharedFutureHandle::new(CountingFuture { calls: calls.clone() });
+
+        let other = handle.clone();
+        let join = thread::spawn(move || *other.await_ref());
+
+        assert_eq!(*handle.await_ref(), 42);
+        assert_eq!(join.join().unwrap(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_shared_future_wraps_lifted_value() {
+        let shared = SharedFuture::new(lift(7u8));
+        assert_eq!(*shared.await_ref(), 7u8);
+    }
+}